@@ -1,20 +1,63 @@
 use std::fmt;
 
-#[cfg_attr(feature = "serde_support", derive(serde::Deserialize, serde::Serialize), serde(from = "Vec<String>", into = "Vec<String>"))]
+/// Types usable as the offset stored in [`DenseStrings`]'s index buffer.
+///
+/// Defaults to `usize`, but `u16`/`u32` are supported so a collection of
+/// many short strings doesn't pay for an 8-byte offset per entry.
+///
+/// Widening back to `usize` is done through [`IndexWidth::widen`] rather
+/// than `Into<usize>`, since `std` only guarantees `From<u8>`/`From<u16>`
+/// for `usize` (it's only guaranteed to be at least 16 bits wide), so
+/// `u32: Into<usize>` doesn't hold on every target.
+pub trait IndexWidth: TryFrom<usize> + Copy {
+    fn widen(self) -> usize;
+}
+
+impl IndexWidth for u16 {
+    fn widen(self) -> usize {
+        self as usize
+    }
+}
+
+impl IndexWidth for u32 {
+    fn widen(self) -> usize {
+        self as usize
+    }
+}
+
+impl IndexWidth for usize {
+    fn widen(self) -> usize {
+        self
+    }
+}
+
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(from = "Vec<String>", into = "Vec<String>", bound = "I: IndexWidth")
+)]
 #[derive(Clone)]
-pub struct DenseStrings {
+pub struct DenseStrings<I = usize> {
     data: Box<[u8]>,
-    indices: Box<[usize]>,
+    indices: Box<[I]>,
 }
 
-impl DenseStrings {
+impl<I: IndexWidth> DenseStrings<I> {
+    /// Builds a `DenseStrings` by concatenating `strings` into a single
+    /// buffer, recording an index entry at each boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the concatenated byte length doesn't fit in `I` (e.g. more
+    /// than `u16::MAX` bytes when `I = u16`). Use [`DenseStrings::new_compact`]
+    /// to pick a width that's guaranteed to fit instead.
     pub fn new(strings: &[impl AsRef<str>]) -> Self {
         let mut data = Vec::new();
         let mut indices = Vec::with_capacity(strings.len().saturating_sub(1));
 
         for (i, string) in strings.iter().enumerate() {
             if i != 0 {
-                indices.push(data.len())
+                indices.push(Self::offset(data.len()))
             }
             data.extend(string.as_ref().bytes())
         }
@@ -25,15 +68,70 @@ impl DenseStrings {
         }
     }
 
+    fn offset(n: usize) -> I {
+        I::try_from(n).unwrap_or_else(|_| panic!("offset {n} does not fit in the index type"))
+    }
+
+    /// Builds a `DenseStrings` directly from its data and index buffers
+    /// without validating them.
+    ///
+    /// # Safety
+    ///
+    /// `data` must be valid UTF-8 in its entirety (it's handed to
+    /// [`DenseStrings::full_str`] via `from_utf8_unchecked` unconditionally).
+    /// `indices` must be non-decreasing, every index must be `<= data.len()`,
+    /// and every index must fall on a UTF-8 char boundary of `data` (so that
+    /// every resulting byte range is valid UTF-8).
+    pub unsafe fn from_raw_parts(data: Box<[u8]>, indices: Box<[I]>) -> Self {
+        Self { data, indices }
+    }
+
+    /// Builds a `DenseStrings` from its data and index buffers, validating
+    /// that the indices are in range, non-decreasing, and split `data` on
+    /// UTF-8 char boundaries.
+    pub fn from_utf8(data: Box<[u8]>, indices: Box<[I]>) -> Result<Self, DenseStringsError> {
+        let s = match std::str::from_utf8(&data) {
+            Ok(s) => s,
+            Err(e) => return Err(DenseStringsError::InvalidUtf8(e.valid_up_to())),
+        };
+
+        let mut prev = 0;
+        for &idx in indices.iter() {
+            let idx = idx.widen();
+            if idx > data.len() {
+                return Err(DenseStringsError::IndexOutOfBounds(idx));
+            }
+            if idx < prev {
+                return Err(DenseStringsError::NonMonotonicIndices(idx));
+            }
+            // Check the boundary against the validated `&str`, not `data`
+            // directly — `[u8]` has no `is_char_boundary`.
+            if !s.is_char_boundary(idx) {
+                return Err(DenseStringsError::InvalidUtf8(idx));
+            }
+            prev = idx;
+        }
+
+        Ok(Self { data, indices })
+    }
+
+    pub fn into_raw_parts(self) -> (Box<[u8]>, Box<[I]>) {
+        (self.data, self.indices)
+    }
+
     fn get_byte_range(&self, i: usize) -> Option<std::ops::Range<usize>> {
-        let start = i.checked_sub(1).map(|i| self.indices.get(i).copied()).unwrap_or(Some(0))?;
-        let end = (i <= self.indices.len()).then_some(self.indices.get(i).copied().unwrap_or(self.data.len()))?;
+        let start = i
+            .checked_sub(1)
+            .map(|i| self.indices.get(i).copied().map(IndexWidth::widen))
+            .unwrap_or(Some(0))?;
+        let end = (i <= self.indices.len())
+            .then_some(self.indices.get(i).copied().map(IndexWidth::widen).unwrap_or(self.data.len()))?;
         Some(start..end)
     }
 
     pub fn get(&self, i: usize) -> Option<&str> {
         let range = self.get_byte_range(i)?;
-        
+
         // SAFETY: data will always contain valid utf8 with the indices in strings.
         let s = unsafe {
             std::str::from_utf8_unchecked(&self.data[range])
@@ -46,7 +144,7 @@ impl DenseStrings {
         self.indices.len() + 1
     }
 
-    pub fn iter(&self) -> DenseStringVecIter {
+    pub fn iter(&self) -> DenseStringVecIter<'_, I> {
         DenseStringVecIter { vec: self, i: 0 }
     }
 
@@ -55,9 +153,159 @@ impl DenseStrings {
             std::str::from_utf8_unchecked(&self.data)
         }
     }
+
+    pub fn builder() -> DenseStringsBuilder<I> {
+        DenseStringsBuilder::new()
+    }
+
+    /// Removes the entries in `range`, returning an iterator over the
+    /// removed entries as owned `String`s (mirroring `Vec<String>::drain`,
+    /// since the backing buffer is compacted immediately and can no longer
+    /// back borrowed `&str`s into the removed region).
+    pub fn drain<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, I> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            std::ops::Bound::Included(&n) => n,
+            std::ops::Bound::Excluded(&n) => n + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            std::ops::Bound::Included(&n) => n + 1,
+            std::ops::Bound::Excluded(&n) => n,
+            std::ops::Bound::Unbounded => len,
+        };
+        assert!(start <= end && end <= len, "drain range out of bounds");
+
+        let removed: Vec<String> = (start..end).map(|i| self.get(i).unwrap().to_owned()).collect();
+
+        if start != end {
+            let byte_start = if start == 0 { 0 } else { self.indices[start - 1].widen() };
+            let byte_end = if end < len { self.indices[end - 1].widen() } else { self.data.len() };
+            let removed_bytes = byte_end - byte_start;
+
+            let mut data = std::mem::take(&mut self.data).into_vec();
+            data.drain(byte_start..byte_end);
+            self.data = data.into_boxed_slice();
+
+            let old_indices = std::mem::take(&mut self.indices);
+            let has_suffix = end < len;
+            let mut new_indices = Vec::with_capacity(old_indices.len() - (end - start).min(old_indices.len()));
+            new_indices.extend_from_slice(&old_indices[..start.saturating_sub(1)]);
+            if start > 0 && has_suffix {
+                new_indices.push(old_indices[start - 1]);
+            }
+            if has_suffix {
+                new_indices.extend(
+                    old_indices[end..]
+                        .iter()
+                        .map(|&v| Self::offset(v.widen() - removed_bytes)),
+                );
+            }
+            self.indices = new_indices.into_boxed_slice();
+        }
+
+        Drain {
+            removed: removed.into_iter(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the `(entry_index, byte_offset)` of the first entry matching
+    /// `pat`, searching entry by entry so a match can never span an entry
+    /// boundary.
+    pub fn find(&self, pat: impl pattern::SearchPattern) -> Option<(usize, usize)> {
+        self.iter()
+            .enumerate()
+            .find_map(|(i, entry)| pat.find_in(entry).map(|offset| (i, offset)))
+    }
+
+    pub fn contains(&self, pat: impl pattern::SearchPattern) -> bool {
+        self.find(pat).is_some()
+    }
+
+    /// Returns every `(entry_index, byte_offset)` where `pat` matches,
+    /// searching entry by entry so a match can never span an entry boundary.
+    pub fn matches<'a>(&'a self, pat: impl pattern::SearchPattern + 'a) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter()
+            .enumerate()
+            .flat_map(move |(i, entry)| pat.match_indices_in(entry).map(move |offset| (i, offset)))
+    }
 }
 
-impl std::ops::Index<usize> for DenseStrings {
+impl DenseStrings<usize> {
+    /// Builds a `DenseStrings`, picking the narrowest index width (`u16`,
+    /// `u32`, or `usize`) that fits the resulting data buffer.
+    pub fn new_compact(strings: &[impl AsRef<str>]) -> DenseStringsCompact {
+        let built = DenseStrings::<usize>::new(strings);
+        let total = built.data.len();
+
+        if total <= u16::MAX as usize {
+            DenseStringsCompact::U16(DenseStrings {
+                data: built.data,
+                indices: built.indices.iter().map(|&v| v as u16).collect(),
+            })
+        } else if total <= u32::MAX as usize {
+            DenseStringsCompact::U32(DenseStrings {
+                data: built.data,
+                indices: built.indices.iter().map(|&v| v as u32).collect(),
+            })
+        } else {
+            DenseStringsCompact::Usize(built)
+        }
+    }
+}
+
+/// The result of [`DenseStrings::new_compact`]: a `DenseStrings` using the
+/// narrowest index width that fits its data.
+pub enum DenseStringsCompact {
+    U16(DenseStrings<u16>),
+    U32(DenseStrings<u32>),
+    Usize(DenseStrings<usize>),
+}
+
+/// Search patterns usable with [`DenseStrings::find`], [`DenseStrings::contains`]
+/// and [`DenseStrings::matches`].
+///
+/// `std::str::pattern::Pattern` (referenced by `str::find`/`str::matches` in
+/// the standard library) is still unstable, so this covers the common `char`
+/// and `&str` pattern kinds directly in terms of the stable `str` methods.
+pub mod pattern {
+    pub trait SearchPattern: Copy {
+        fn find_in(self, haystack: &str) -> Option<usize>;
+
+        fn match_indices_in<'h>(self, haystack: &'h str) -> impl Iterator<Item = usize> + 'h
+        where
+            Self: 'h;
+    }
+
+    impl SearchPattern for char {
+        fn find_in(self, haystack: &str) -> Option<usize> {
+            haystack.find(self)
+        }
+
+        fn match_indices_in<'h>(self, haystack: &'h str) -> impl Iterator<Item = usize> + 'h
+        where
+            Self: 'h,
+        {
+            haystack.match_indices(self).map(move |(i, _)| i)
+        }
+    }
+
+    impl SearchPattern for &str {
+        fn find_in(self, haystack: &str) -> Option<usize> {
+            haystack.find(self)
+        }
+
+        fn match_indices_in<'h>(self, haystack: &'h str) -> impl Iterator<Item = usize> + 'h
+        where
+            Self: 'h,
+        {
+            haystack.match_indices(self).map(move |(i, _)| i)
+        }
+    }
+}
+
+impl<I: IndexWidth> std::ops::Index<usize> for DenseStrings<I> {
     type Output = str;
 
     fn index(&self, index: usize) -> &Self::Output {
@@ -65,33 +313,77 @@ impl std::ops::Index<usize> for DenseStrings {
     }
 }
 
-impl fmt::Debug for DenseStrings {
+impl<I: IndexWidth> fmt::Debug for DenseStrings<I> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
 }
 
-impl std::hash::Hash for DenseStrings {
+impl<I: IndexWidth> std::hash::Hash for DenseStrings<I> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.full_str().hash(state);
         self.len().hash(state);
+        for entry in self.iter() {
+            entry.hash(state);
+        }
     }
 }
 
-impl PartialEq for DenseStrings {
+impl<I: IndexWidth> PartialEq for DenseStrings<I> {
     fn eq(&self, other: &Self) -> bool {
-        self.full_str() == other.full_str() && self.len() == other.len()
+        self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
 
-impl Eq for DenseStrings {}
+impl<I: IndexWidth> Eq for DenseStrings<I> {}
+
+impl<I: IndexWidth> PartialOrd for DenseStrings<I> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I: IndexWidth> Ord for DenseStrings<I> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// An error returned by [`DenseStrings::from_utf8`] when the given data and
+/// index buffers don't form a valid `DenseStrings`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DenseStringsError {
+    /// An index was greater than the length of the data buffer.
+    IndexOutOfBounds(usize),
+    /// An index was smaller than the index preceding it.
+    NonMonotonicIndices(usize),
+    /// The data buffer isn't valid UTF-8, or an index splits it mid-character.
+    InvalidUtf8(usize),
+}
 
-pub struct DenseStringVecIter<'a> {
-    vec: &'a DenseStrings,
+impl fmt::Display for DenseStringsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::IndexOutOfBounds(offset) => {
+                write!(f, "index {offset} is out of bounds of the data buffer")
+            }
+            Self::NonMonotonicIndices(offset) => {
+                write!(f, "index {offset} is smaller than the preceding index")
+            }
+            Self::InvalidUtf8(offset) => {
+                write!(f, "data is not valid utf-8 at byte offset {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DenseStringsError {}
+
+pub struct DenseStringVecIter<'a, I = usize> {
+    vec: &'a DenseStrings<I>,
     i: usize,
 }
 
-impl<'a> Iterator for DenseStringVecIter<'a> {
+impl<'a, I: IndexWidth> Iterator for DenseStringVecIter<'a, I> {
     type Item = &'a str;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -106,26 +398,145 @@ impl<'a> Iterator for DenseStringVecIter<'a> {
     }
 }
 
-impl<'a> ExactSizeIterator for DenseStringVecIter<'a> {}
+impl<'a, I: IndexWidth> ExactSizeIterator for DenseStringVecIter<'a, I> {}
+
+/// A draining iterator over the entries removed by [`DenseStrings::drain`].
+///
+/// The removed entries are detached from `DenseStrings` as soon as `drain`
+/// is called, so dropping this iterator early (without exhausting it) still
+/// removes them.
+pub struct Drain<'a, I = usize> {
+    removed: std::vec::IntoIter<String>,
+    _marker: std::marker::PhantomData<&'a mut DenseStrings<I>>,
+}
+
+impl<'a, I> Iterator for Drain<'a, I> {
+    type Item = String;
 
-impl From<Vec<String>> for DenseStrings {
+    fn next(&mut self) -> Option<Self::Item> {
+        self.removed.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.removed.size_hint()
+    }
+}
+
+impl<'a, I> ExactSizeIterator for Drain<'a, I> {}
+
+impl<I: IndexWidth> From<Vec<String>> for DenseStrings<I> {
     fn from(value: Vec<String>) -> Self {
         Self::new(&value)
     }
 }
 
-impl From<DenseStrings> for Vec<String> {
-    fn from(value: DenseStrings) -> Self {
+impl<I: IndexWidth> From<DenseStrings<I>> for Vec<String> {
+    fn from(value: DenseStrings<I>) -> Self {
         value.iter().map(String::from).collect()
     }
 }
 
+/// Incrementally builds a [`DenseStrings`], growing its buffers with amortized
+/// capacity the same way [`String`] does, rather than materializing every
+/// entry up front like [`DenseStrings::new`].
+#[derive(Clone)]
+pub struct DenseStringsBuilder<I = usize> {
+    data: Vec<u8>,
+    indices: Vec<I>,
+    len: usize,
+}
+
+impl<I> Default for DenseStringsBuilder<I> {
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            indices: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<I: IndexWidth> DenseStringsBuilder<I> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(bytes: usize, count: usize) -> Self {
+        Self {
+            data: Vec::with_capacity(bytes),
+            indices: Vec::with_capacity(count.saturating_sub(1)),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends `s` as a new entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer's byte length after appending `s` doesn't fit in
+    /// `I` (e.g. more than `u16::MAX` bytes when `I = u16`).
+    pub fn push(&mut self, s: impl AsRef<str>) {
+        if self.len != 0 {
+            self.indices.push(DenseStrings::<I>::offset(self.data.len()));
+        }
+        self.data.extend_from_slice(s.as_ref().as_bytes());
+        self.len += 1;
+    }
+
+    /// Appends every string in `strings` as a new entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer's byte length doesn't fit in `I` partway through
+    /// (see [`DenseStringsBuilder::push`]); entries pushed before the
+    /// offending one remain in the builder.
+    pub fn extend<It: IntoIterator<Item = S>, S: AsRef<str>>(&mut self, strings: It) {
+        for s in strings {
+            self.push(s);
+        }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.data.reserve_exact(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+        self.indices.shrink_to_fit();
+    }
+
+    pub fn finish(self) -> DenseStrings<I> {
+        DenseStrings {
+            data: self.data.into_boxed_slice(),
+            indices: self.indices.into_boxed_slice(),
+        }
+    }
+}
+
+impl<I: IndexWidth> From<DenseStringsBuilder<I>> for DenseStrings<I> {
+    fn from(builder: DenseStringsBuilder<I>) -> Self {
+        builder.finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
     fn basic_test() {
-        let vec = DenseStrings::new(&[
+        let vec = DenseStrings::<usize>::new(&[
             "",
             "",
             "",
@@ -134,7 +545,7 @@ mod tests {
             "baz",
             "",
         ]);
-        
+
         let mut iter = vec.iter();
 
         assert_eq!(iter.next(), Some(""));
@@ -146,4 +557,169 @@ mod tests {
         assert_eq!(iter.next(), Some(""));
         assert_eq!(iter.next(), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn builder_test() {
+        let mut builder = DenseStringsBuilder::<usize>::with_capacity(9, 3);
+        builder.push("foo");
+        builder.extend(["bar", "baz"]);
+
+        let vec = builder.finish();
+        let mut iter = vec.iter();
+
+        assert_eq!(iter.next(), Some("foo"));
+        assert_eq!(iter.next(), Some("bar"));
+        assert_eq!(iter.next(), Some("baz"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn from_utf8_round_trip() {
+        let vec = DenseStrings::<usize>::new(&["foo", "bar", "baz"]);
+        let (data, indices) = vec.into_raw_parts();
+
+        let vec = DenseStrings::from_utf8(data, indices).unwrap();
+        assert_eq!(vec.iter().collect::<Vec<_>>(), ["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn from_utf8_rejects_bad_indices() {
+        let data = b"foobar".to_vec().into_boxed_slice();
+
+        assert_eq!(
+            DenseStrings::<usize>::from_utf8(data.clone(), Box::new([10])),
+            Err(DenseStringsError::IndexOutOfBounds(10))
+        );
+        assert_eq!(
+            DenseStrings::<usize>::from_utf8(data.clone(), Box::new([3, 1])),
+            Err(DenseStringsError::NonMonotonicIndices(1))
+        );
+
+        let mut split = vec![0u8; 4];
+        'é'.encode_utf8(&mut split[0..2]);
+        'é'.encode_utf8(&mut split[2..4]);
+        assert_eq!(
+            DenseStrings::<usize>::from_utf8(split.into_boxed_slice(), Box::new([1])),
+            Err(DenseStringsError::InvalidUtf8(1))
+        );
+    }
+
+    #[test]
+    fn eq_is_element_wise() {
+        let a = DenseStrings::<usize>::new(&["ab", "c"]);
+        let b = DenseStrings::<usize>::new(&["a", "bc"]);
+
+        assert_ne!(a, b);
+        assert_eq!(a, DenseStrings::<usize>::new(&["ab", "c"]));
+
+        fn hash_of(v: &DenseStrings) -> u64 {
+            use std::hash::{Hash, Hasher};
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+        assert_ne!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn ord_is_lexicographic() {
+        let a = DenseStrings::<usize>::new(&["a", "b"]);
+        let b = DenseStrings::<usize>::new(&["a", "c"]);
+        let c = DenseStrings::<usize>::new(&["a"]);
+
+        assert!(a < b);
+        assert!(c < a);
+    }
+
+    #[test]
+    fn drain_middle() {
+        let mut vec = DenseStrings::<usize>::new(&["a", "b", "c", "d", "e"]);
+        let removed: Vec<String> = vec.drain(1..3).collect();
+        assert_eq!(removed, ["b", "c"]);
+        assert_eq!(vec.iter().collect::<Vec<_>>(), ["a", "d", "e"]);
+    }
+
+    #[test]
+    fn drain_front() {
+        let mut vec = DenseStrings::<usize>::new(&["a", "b", "c"]);
+        vec.drain(0..1).for_each(drop);
+        assert_eq!(vec.iter().collect::<Vec<_>>(), ["b", "c"]);
+    }
+
+    #[test]
+    fn drain_back() {
+        let mut vec = DenseStrings::<usize>::new(&["a", "b", "c"]);
+        vec.drain(2..3).for_each(drop);
+        assert_eq!(vec.iter().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn drain_all() {
+        // Like `DenseStrings::<usize>::new(&[])`, draining every entry still leaves a
+        // single empty entry behind, since `len()` is always `indices.len() + 1`.
+        let mut vec = DenseStrings::<usize>::new(&["a", "b", "c"]);
+        vec.drain(..).for_each(drop);
+        assert_eq!(vec.iter().collect::<Vec<_>>(), [""]);
+    }
+
+    #[test]
+    fn drain_empty_range() {
+        let mut vec = DenseStrings::<usize>::new(&["a", "b", "c"]);
+        assert_eq!(vec.drain(1..1).count(), 0);
+        assert_eq!(vec.iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn drain_dropped_without_iterating_still_removes() {
+        let mut vec = DenseStrings::<usize>::new(&["a", "b", "c"]);
+        drop(vec.drain(1..2));
+        assert_eq!(vec.iter().collect::<Vec<_>>(), ["a", "c"]);
+    }
+
+    #[test]
+    fn find_does_not_cross_entry_boundaries() {
+        let vec = DenseStrings::<usize>::new(&["fo", "obar"]);
+
+        // "foo" only exists by concatenating entries, so it must not match.
+        assert!(!vec.contains("foo"));
+        assert_eq!(vec.find("obar"), Some((1, 0)));
+        assert_eq!(vec.find('b'), Some((1, 1)));
+    }
+
+    #[test]
+    fn matches_collects_every_hit() {
+        let vec = DenseStrings::<usize>::new(&["banana", "anagram"]);
+
+        let hits: Vec<_> = vec.matches("ana").collect();
+        assert_eq!(hits, [(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn compact_index_width_u16() {
+        let vec = DenseStrings::new_compact(&["foo", "bar", "baz"]);
+        let DenseStringsCompact::U16(vec) = vec else {
+            panic!("expected a u16-indexed DenseStrings for a tiny buffer");
+        };
+        assert_eq!(vec.iter().collect::<Vec<_>>(), ["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn compact_index_width_u32() {
+        let long = "x".repeat(u16::MAX as usize + 1);
+        let vec = DenseStrings::new_compact(&[long.as_str(), "bar", "baz"]);
+        let DenseStringsCompact::U32(vec) = vec else {
+            panic!("expected a u32-indexed DenseStrings for a buffer larger than u16::MAX");
+        };
+        assert_eq!(vec.get(1), Some("bar"));
+        assert_eq!(vec.iter().collect::<Vec<_>>(), [long.as_str(), "bar", "baz"]);
+    }
+
+    #[test]
+    fn narrow_index_width_round_trips() {
+        let vec = DenseStrings::<u16>::new(&["foo", "bar", "baz"]);
+        let (data, indices) = vec.into_raw_parts();
+
+        let vec = DenseStrings::<u16>::from_utf8(data, indices).unwrap();
+        assert_eq!(vec.iter().collect::<Vec<_>>(), ["foo", "bar", "baz"]);
+    }
+}